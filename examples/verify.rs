@@ -1,13 +1,14 @@
 /* examples/verify.rs */
 
-use totp::{current_unix_time, generate_combined_token, verify_combined_token};
+use totp::{current_unix_time, generate_combined_token, verify_combined_token, Algorithm, Secret};
 
 fn main() {
-	let seeds = ["a", "b", "c", "d", "e", "f"];
+	let seeds = ["a", "b", "c", "d", "e", "f"].map(Secret::raw);
+	let seeds = [&seeds[0], &seeds[1], &seeds[2], &seeds[3], &seeds[4], &seeds[5]];
 	let time = current_unix_time();
 	let window = 15;
-	let token = generate_combined_token(seeds, time, window);
+	let token = generate_combined_token(seeds, time, window, 6, "s", Algorithm::Sha1);
 	println!("Generated: {}", token);
-	let ok = verify_combined_token(seeds, time, &token, window, 2, "s");
+	let ok = verify_combined_token(seeds, time, &token, window, 6, 2, "s", Algorithm::Sha1);
 	println!("Verified: {}", ok);
 }
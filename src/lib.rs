@@ -1,32 +1,143 @@
 /* src/lib.rs */
 
+#![cfg_attr(not(feature = "std"), no_std)]
+
+#[cfg(not(feature = "std"))]
+extern crate alloc;
+
+mod provisioning;
+mod secret;
 mod totp;
-pub use totp::{current_unix_time, generate_combined_token, verify_combined_token};
+pub use provisioning::{provisioning_urls, ProvisioningMetadata};
+pub use secret::{Secret, SecretError};
+pub use totp::{generate_combined_token, next_step, ttl, verify_combined_token, Algorithm};
+
+#[cfg(feature = "std")]
+pub use totp::{current_unix_time, generate_combined_token_current, verify_combined_token_current};
+
+#[cfg(all(feature = "qr", feature = "std"))]
+pub use provisioning::qr;
+
+#[cfg(not(feature = "std"))]
+use alloc::{string::String, vec::Vec};
 
 #[cfg(target_arch = "wasm32")]
 use wasm_bindgen::prelude::*;
 
-#[cfg_attr(target_arch = "wasm32", wasm_bindgen)]
-pub fn wasm_generate_combined_token(seeds: Vec<String>, time: u64, window: u64) -> String {
-	let mut arr = ["", "", "", "", "", ""];
-	for (i, s) in seeds.iter().enumerate().take(6) {
-		arr[i] = s;
+/// Build the six [`Secret`]s the wasm bindings operate on, decoding each
+/// seed as Base32 when `base32` is set and treating it as raw bytes otherwise.
+///
+/// Returns `Err` rather than panicking if a seed is not valid Base32, so an
+/// invalid user-supplied secret can't abort the wasm call.
+fn wasm_secrets(seeds: Vec<String>, base32: bool) -> Result<[Secret; 6], SecretError> {
+	let mut decoded: Vec<Secret> = Vec::with_capacity(6);
+	for s in seeds.iter().take(6) {
+		decoded.push(if base32 { Secret::from_base32(s)? } else { Secret::raw(s) });
+	}
+	while decoded.len() < 6 {
+		decoded.push(Secret::raw(""));
 	}
-	generate_combined_token(arr, time, window)
+	let mut it = decoded.into_iter();
+	Ok(core::array::from_fn(|_| it.next().unwrap()))
+}
+
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen)]
+pub fn wasm_generate_combined_token(
+	seeds: Vec<String>,
+	time: u64,
+	window: u64,
+	digits: u32,
+	unit: String,
+	algorithm: String,
+	base32: bool,
+) -> String {
+	let Ok(secrets) = wasm_secrets(seeds, base32) else {
+		return String::new();
+	};
+	let arr = [&secrets[0], &secrets[1], &secrets[2], &secrets[3], &secrets[4], &secrets[5]];
+	generate_combined_token(arr, time, window, digits, &unit, Algorithm::from_name(&algorithm))
 }
 
+#[allow(clippy::too_many_arguments)]
 #[cfg_attr(target_arch = "wasm32", wasm_bindgen)]
 pub fn wasm_verify_combined_token(
 	seeds: Vec<String>,
 	time: u64,
 	token: String,
 	window: u64,
+	digits: u32,
 	allowed_windows: u32,
 	unit: String,
+	algorithm: String,
+	base32: bool,
 ) -> bool {
-	let mut arr = ["", "", "", "", "", ""];
-	for (i, s) in seeds.iter().enumerate().take(6) {
-		arr[i] = s;
-	}
-	verify_combined_token(arr, time, &token, window, allowed_windows, &unit)
+	let Ok(secrets) = wasm_secrets(seeds, base32) else {
+		return false;
+	};
+	let arr = [&secrets[0], &secrets[1], &secrets[2], &secrets[3], &secrets[4], &secrets[5]];
+	verify_combined_token(
+		arr,
+		time,
+		&token,
+		window,
+		digits,
+		allowed_windows,
+		&unit,
+		Algorithm::from_name(&algorithm),
+	)
+}
+
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen)]
+pub fn wasm_ttl(time: u64, window: u64, unit: String) -> u64 {
+	ttl(time, window, &unit)
+}
+
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen)]
+pub fn wasm_next_step(time: u64, window: u64, unit: String) -> u64 {
+	next_step(time, window, &unit)
+}
+
+#[cfg(feature = "std")]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen)]
+pub fn wasm_generate_combined_token_current(
+	seeds: Vec<String>,
+	window: u64,
+	digits: u32,
+	unit: String,
+	algorithm: String,
+	base32: bool,
+) -> String {
+	let Ok(secrets) = wasm_secrets(seeds, base32) else {
+		return String::new();
+	};
+	let arr = [&secrets[0], &secrets[1], &secrets[2], &secrets[3], &secrets[4], &secrets[5]];
+	generate_combined_token_current(arr, window, digits, &unit, Algorithm::from_name(&algorithm))
+}
+
+#[cfg(feature = "std")]
+#[allow(clippy::too_many_arguments)]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen)]
+pub fn wasm_verify_combined_token_current(
+	seeds: Vec<String>,
+	token: String,
+	window: u64,
+	digits: u32,
+	allowed_windows: u32,
+	unit: String,
+	algorithm: String,
+	base32: bool,
+) -> bool {
+	let Ok(secrets) = wasm_secrets(seeds, base32) else {
+		return false;
+	};
+	let arr = [&secrets[0], &secrets[1], &secrets[2], &secrets[3], &secrets[4], &secrets[5]];
+	verify_combined_token_current(
+		arr,
+		&token,
+		window,
+		digits,
+		allowed_windows,
+		&unit,
+		Algorithm::from_name(&algorithm),
+	)
 }
@@ -0,0 +1,141 @@
+/* src/provisioning.rs */
+
+use crate::secret::Secret;
+use crate::totp::Algorithm;
+
+#[cfg(not(feature = "std"))]
+use alloc::{format, string::String};
+
+/// Metadata describing how a seed should be presented when provisioning an
+/// authenticator app via an `otpauth://` URL.
+///
+/// # Example
+///
+/// ```
+/// use totp::{Algorithm, ProvisioningMetadata};
+///
+/// let meta = ProvisioningMetadata {
+///     issuer: "Example".to_string(),
+///     account_name: "alice@example.com".to_string(),
+///     algorithm: Algorithm::Sha1,
+///     digits: 6,
+///     period: 30,
+/// };
+/// ```
+#[derive(Debug, Clone)]
+pub struct ProvisioningMetadata {
+	pub issuer: String,
+	pub account_name: String,
+	pub algorithm: Algorithm,
+	pub digits: u32,
+	pub period: u64,
+}
+
+/// Build the six `otpauth://totp/...` provisioning URLs for a combined
+/// token's seeds.
+///
+/// Since a combined token is made of six independent seeds, each seed gets
+/// its own labeled URL (`account_name` suffixed `-1` through `-6`) so every
+/// seed can be enrolled as a separate entry in a normal authenticator app.
+///
+/// # Arguments
+///
+/// * `seeds` - The six seeds the combined token is generated from.
+/// * `meta` - Issuer, account, algorithm, digit count, and period metadata shared by all six URLs.
+///
+/// # Returns
+///
+/// Six `otpauth://` URLs, one per seed, in the same order as `seeds`.
+pub fn provisioning_urls(seeds: [&Secret; 6], meta: &ProvisioningMetadata) -> [String; 6] {
+	core::array::from_fn(|i| {
+		let label = format!("{}-{}", meta.account_name, i + 1);
+		format!(
+			"otpauth://totp/{}:{}?secret={}&issuer={}&algorithm={}&digits={}&period={}",
+			percent_encode(&meta.issuer),
+			percent_encode(&label),
+			seeds[i].to_base32(),
+			percent_encode(&meta.issuer),
+			meta.algorithm.name(),
+			meta.digits,
+			meta.period,
+		)
+	})
+}
+
+/// Percent-encode the label/issuer components of an `otpauth://` URL,
+/// leaving alphanumerics and `-_.~` unescaped per RFC 3986.
+fn percent_encode(input: &str) -> String {
+	let mut out = String::with_capacity(input.len());
+	for byte in input.as_bytes() {
+		match byte {
+			b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'_' | b'.' | b'~' => out.push(*byte as char),
+			_ => out.push_str(&format!("%{:02X}", byte)),
+		}
+	}
+	out
+}
+
+/// Render a provisioning URL as a QR code, behind the optional `qr` feature.
+///
+/// `qr` depends on image encoding and therefore requires `std`: enabling
+/// the `qr` feature also enables the `std` feature.
+#[cfg(all(feature = "qr", feature = "std"))]
+pub mod qr {
+	use qrcode::render::svg;
+	use qrcode::QrCode;
+
+	/// Render `url` as a QR code PNG, returning the encoded image bytes.
+	pub fn to_png(url: &str) -> Result<Vec<u8>, qrcode::types::QrError> {
+		let code = QrCode::new(url.as_bytes())?;
+		let image = code.render::<image::Luma<u8>>().build();
+		let mut bytes = Vec::new();
+		image::DynamicImage::ImageLuma8(image)
+			.write_to(&mut std::io::Cursor::new(&mut bytes), image::ImageFormat::Png)
+			.expect("encoding a QR code as PNG should never fail");
+		Ok(bytes)
+	}
+
+	/// Render `url` as a QR code SVG string.
+	pub fn to_svg(url: &str) -> Result<String, qrcode::types::QrError> {
+		let code = QrCode::new(url.as_bytes())?;
+		Ok(code.render::<svg::Color>().build())
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn provisioning_urls_are_labeled_one_through_six_in_order() {
+		let seeds = ["a", "b", "c", "d", "e", "f"].map(Secret::raw);
+		let seeds = [&seeds[0], &seeds[1], &seeds[2], &seeds[3], &seeds[4], &seeds[5]];
+		let meta = ProvisioningMetadata {
+			issuer: "Example".into(),
+			account_name: "alice@example.com".into(),
+			algorithm: Algorithm::Sha1,
+			digits: 6,
+			period: 30,
+		};
+		let urls = provisioning_urls(seeds, &meta);
+		for (i, url) in urls.iter().enumerate() {
+			let suffix = format!("alice%40example.com-{}", i + 1);
+			assert!(url.starts_with("otpauth://totp/Example:"));
+			assert!(url.contains(&suffix), "expected {} to contain {}", url, suffix);
+			assert!(url.contains(&format!("secret={}", seeds[i].to_base32())));
+			assert!(url.contains("algorithm=SHA1"));
+			assert!(url.contains("digits=6"));
+			assert!(url.contains("period=30"));
+		}
+	}
+
+	#[test]
+	fn percent_encode_leaves_unreserved_characters_unescaped() {
+		assert_eq!(percent_encode("Example-Issuer_1.0~x"), "Example-Issuer_1.0~x");
+	}
+
+	#[test]
+	fn percent_encode_escapes_reserved_characters() {
+		assert_eq!(percent_encode("alice@example.com"), "alice%40example.com");
+	}
+}
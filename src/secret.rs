@@ -0,0 +1,148 @@
+/* src/secret.rs */
+
+#[cfg(not(feature = "std"))]
+use alloc::{string::String, vec::Vec};
+
+const BASE32_ALPHABET: &[u8] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZ234567";
+
+/// Error returned when a Base32-encoded secret cannot be decoded.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SecretError {
+	/// The input contained a character outside the RFC 4648 Base32 alphabet,
+	/// or had leftover non-zero padding bits.
+	InvalidBase32,
+}
+
+impl core::fmt::Display for SecretError {
+	fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+		match self {
+			SecretError::InvalidBase32 => write!(f, "invalid Base32-encoded secret"),
+		}
+	}
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for SecretError {}
+
+/// A shared TOTP secret, resolved to the raw bytes used as the HMAC key.
+///
+/// Authenticator apps such as Google Authenticator and Authy, along with
+/// `otpauth://` provisioning URLs, encode seeds as RFC 4648 Base32 text
+/// rather than raw bytes. `Secret` captures how a seed string should be
+/// interpreted so both forms can be used interchangeably.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Secret(Vec<u8>);
+
+impl Secret {
+	/// Treat `seed` as raw UTF-8 bytes, matching this crate's original
+	/// behavior. Use this for seeds that were not Base32-encoded.
+	pub fn raw(seed: &str) -> Self {
+		Secret(seed.as_bytes().to_vec())
+	}
+
+	/// Decode `seed` as RFC 4648 Base32 text into the actual secret bytes.
+	///
+	/// Padding (`=`) is optional, matching the unpadded secrets most
+	/// authenticator apps generate. Returns [`SecretError::InvalidBase32`]
+	/// if `seed` contains characters outside the Base32 alphabet or has
+	/// non-zero leftover padding bits.
+	pub fn from_base32(seed: &str) -> Result<Self, SecretError> {
+		base32_decode(seed).map(Secret)
+	}
+
+	/// Encode the secret bytes as RFC 4648 Base32 text (no padding), the
+	/// form expected by the `secret` parameter of an `otpauth://` URL.
+	pub fn to_base32(&self) -> String {
+		base32_encode(&self.0)
+	}
+
+	pub(crate) fn as_bytes(&self) -> &[u8] {
+		&self.0
+	}
+}
+
+fn base32_decode(input: &str) -> Result<Vec<u8>, SecretError> {
+	let trimmed = input.trim_end_matches('=');
+	let mut bits: u32 = 0;
+	let mut bit_count: u32 = 0;
+	let mut out = Vec::with_capacity(trimmed.len() * 5 / 8);
+
+	for c in trimmed.chars() {
+		if !c.is_ascii() {
+			return Err(SecretError::InvalidBase32);
+		}
+		let value = BASE32_ALPHABET
+			.iter()
+			.position(|&b| b == c.to_ascii_uppercase() as u8)
+			.ok_or(SecretError::InvalidBase32)? as u32;
+		bits = (bits << 5) | value;
+		bit_count += 5;
+		if bit_count >= 8 {
+			bit_count -= 8;
+			out.push((bits >> bit_count) as u8);
+		}
+	}
+
+	if bit_count > 0 && (bits & ((1 << bit_count) - 1)) != 0 {
+		return Err(SecretError::InvalidBase32);
+	}
+
+	Ok(out)
+}
+
+fn base32_encode(input: &[u8]) -> String {
+	let mut bits: u32 = 0;
+	let mut bit_count: u32 = 0;
+	let mut out = String::with_capacity((input.len() * 8).div_ceil(5));
+
+	for &byte in input {
+		bits = (bits << 8) | byte as u32;
+		bit_count += 8;
+		while bit_count >= 5 {
+			bit_count -= 5;
+			out.push(BASE32_ALPHABET[((bits >> bit_count) & 0x1f) as usize] as char);
+		}
+	}
+
+	if bit_count > 0 {
+		out.push(BASE32_ALPHABET[((bits << (5 - bit_count)) & 0x1f) as usize] as char);
+	}
+
+	out
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn raw_uses_utf8_bytes_verbatim() {
+		assert_eq!(Secret::raw("hello").as_bytes(), b"hello");
+	}
+
+	#[test]
+	fn base32_round_trips_through_encode_and_decode() {
+		let secret = Secret::raw("12345678901234567890");
+		let reencoded = Secret::from_base32(&secret.to_base32()).unwrap();
+		assert_eq!(secret, reencoded);
+	}
+
+	#[test]
+	fn from_base32_decodes_a_known_secret() {
+		// "JBSWY3DPEHPK3PXP" is the canonical Base32 encoding of "Hello!\xDE\xAD\xBE\xEF".
+		let secret = Secret::from_base32("JBSWY3DPEHPK3PXP").unwrap();
+		assert_eq!(secret.as_bytes(), b"Hello!\xDE\xAD\xBE\xEF");
+	}
+
+	#[test]
+	fn from_base32_rejects_characters_outside_the_alphabet() {
+		assert_eq!(Secret::from_base32("not-base32!"), Err(SecretError::InvalidBase32));
+	}
+
+	#[test]
+	fn from_base32_rejects_non_ascii_that_would_alias_into_the_alphabet() {
+		// 'Ł' (U+0141) truncates to the ASCII byte 'A' (0x41) under a naive
+		// `as u8` cast; it must be rejected rather than silently decoded.
+		assert_eq!(Secret::from_base32("Ł"), Err(SecretError::InvalidBase32));
+	}
+}
@@ -1,49 +1,136 @@
 /* src/totp.rs */
 
+use crate::secret::Secret;
 use hmac::{Hmac, Mac};
 use sha1::Sha1;
+use sha2::{Sha256, Sha512};
+
+#[cfg(not(feature = "std"))]
+use alloc::{format, string::String, vec, vec::Vec};
+
+#[cfg(feature = "std")]
 use std::time::{SystemTime, UNIX_EPOCH};
 
 type HmacSha1 = Hmac<Sha1>;
+type HmacSha256 = Hmac<Sha256>;
+type HmacSha512 = Hmac<Sha512>;
+
+/// The HMAC hash algorithm used to derive a TOTP token, as defined by RFC 6238.
+///
+/// `Sha1` is the default, since it is what existing authenticator apps and
+/// previously issued tokens in this crate expect.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Algorithm {
+	#[default]
+	Sha1,
+	Sha256,
+	Sha512,
+}
+
+impl Algorithm {
+	/// Parse an algorithm name such as `"SHA1"`, `"SHA256"`, or `"SHA512"`.
+	///
+	/// Matching is case-insensitive. Falls back to [`Algorithm::Sha1`] for
+	/// any unrecognized name, so callers (including the wasm bindings) don't
+	/// need to handle an error case for this rarely-misconfigured value.
+	pub fn from_name(name: &str) -> Self {
+		match name.to_ascii_uppercase().as_str() {
+			"SHA256" => Algorithm::Sha256,
+			"SHA512" => Algorithm::Sha512,
+			_ => Algorithm::Sha1,
+		}
+	}
+
+	/// The canonical name used in `otpauth://` URLs and the wasm bindings.
+	pub fn name(&self) -> &'static str {
+		match self {
+			Algorithm::Sha1 => "SHA1",
+			Algorithm::Sha256 => "SHA256",
+			Algorithm::Sha512 => "SHA512",
+		}
+	}
+}
+
+/// Convert a time unit label into the number of milliseconds it represents.
+///
+/// Recognized units are `"s"` (seconds), `"m"` (minutes), and `"ms"`
+/// (milliseconds); any other value is treated as seconds. Expressing this as
+/// an integer millisecond factor keeps counter selection exact integer
+/// arithmetic instead of floating-point division.
+fn unit_millis(unit: &str) -> u64 {
+	match unit {
+		"m" => 60_000,
+		"ms" => 1,
+		_ => 1_000,
+	}
+}
+
+/// Compute the HMAC of `counter` under `seed` using `algorithm`, returning
+/// the raw digest bytes.
+fn hmac_digest(seed: &Secret, counter: u64, algorithm: Algorithm) -> Vec<u8> {
+	match algorithm {
+		Algorithm::Sha1 => {
+			let mut mac = HmacSha1::new_from_slice(seed.as_bytes()).unwrap();
+			mac.update(&counter.to_be_bytes());
+			mac.finalize().into_bytes().to_vec()
+		}
+		Algorithm::Sha256 => {
+			let mut mac = HmacSha256::new_from_slice(seed.as_bytes()).unwrap();
+			mac.update(&counter.to_be_bytes());
+			mac.finalize().into_bytes().to_vec()
+		}
+		Algorithm::Sha512 => {
+			let mut mac = HmacSha512::new_from_slice(seed.as_bytes()).unwrap();
+			mac.update(&counter.to_be_bytes());
+			mac.finalize().into_bytes().to_vec()
+		}
+	}
+}
 
-/// Generate a single 6-digit TOTP token.
+/// Generate a single TOTP token.
 ///
 /// # Arguments
 ///
-/// * `seed` - The shared secret seed string.
+/// * `seed` - The shared secret.
 /// * `time` - The current UNIX timestamp in seconds.
-/// * `window` - The time step window size in seconds.
+/// * `window` - The time step window size, expressed in `unit`s.
+/// * `digits` - The number of digits the token should have (typically 6, 7, or 8).
+/// * `unit` - The unit `window` is expressed in: `"s"`, `"m"`, or `"ms"`.
+/// * `algorithm` - The HMAC hash algorithm to derive the token with.
 ///
 /// # Returns
 ///
-/// A 6-digit unsigned integer token.
+/// A `digits`-digit unsigned integer token.
 ///
 /// # Example
 ///
-/// ```
-/// let token = generate_token("secret", 1700000000, 30);
+/// ```ignore
+/// // `generate_token` is private; this shows the call shape used internally
+/// // by `generate_combined_token`.
+/// let token = generate_token(&Secret::raw("secret"), 1700000000, 30, 6, "s", Algorithm::Sha1);
 /// println!("{:06}", token);
 /// ```
-fn generate_token(seed: &str, time: u64, window: u64) -> u32 {
-	let counter = time / window;
-	let mut mac = HmacSha1::new_from_slice(seed.as_bytes()).unwrap();
-	mac.update(&counter.to_be_bytes());
-	let hash = mac.finalize().into_bytes();
+fn generate_token(seed: &Secret, time: u64, window: u64, digits: u32, unit: &str, algorithm: Algorithm) -> u32 {
+	let counter = (time * 1000) / (window * unit_millis(unit));
+	let hash = hmac_digest(seed, counter, algorithm);
 	let offset = (hash[hash.len() - 1] & 0x0f) as usize;
 	let code = ((u32::from(hash[offset]) & 0x7f) << 24)
 		| ((u32::from(hash[offset + 1]) & 0xff) << 16)
 		| ((u32::from(hash[offset + 2]) & 0xff) << 8)
 		| (u32::from(hash[offset + 3]) & 0xff);
-	code % 1_000_000
+	code % 10u32.pow(digits)
 }
 
 /// Generate a combined TOTP token from six different seeds.
 ///
 /// # Arguments
 ///
-/// * `seeds` - An array of six secret seeds.
+/// * `seeds` - An array of six shared secrets.
 /// * `time` - The current UNIX timestamp in seconds.
-/// * `window` - The time step window size in seconds.
+/// * `window` - The time step window size, expressed in `unit`s.
+/// * `digits` - The number of digits each token should have (typically 6, 7, or 8).
+/// * `unit` - The unit `window` is expressed in: `"s"`, `"m"`, or `"ms"`.
+/// * `algorithm` - The HMAC hash algorithm to derive each token with.
 ///
 /// # Returns
 ///
@@ -52,14 +139,28 @@ fn generate_token(seed: &str, time: u64, window: u64) -> u32 {
 /// # Example
 ///
 /// ```
-/// let seeds = ["a", "b", "c", "d", "e", "f"];
-/// let token = generate_combined_token(seeds, 1700000000, 30);
+/// use totp::{generate_combined_token, Algorithm, Secret};
+///
+/// let seeds = [
+///     Secret::raw("a"), Secret::raw("b"), Secret::raw("c"),
+///     Secret::raw("d"), Secret::raw("e"), Secret::raw("f"),
+/// ];
+/// let seeds = [&seeds[0], &seeds[1], &seeds[2], &seeds[3], &seeds[4], &seeds[5]];
+/// let token = generate_combined_token(seeds, 1700000000, 30, 6, "s", Algorithm::Sha1);
 /// println!("{}", token); // "123456-654321-..."
 /// ```
-pub fn generate_combined_token(seeds: [&str; 6], time: u64, window: u64) -> String {
+pub fn generate_combined_token(
+	seeds: [&Secret; 6],
+	time: u64,
+	window: u64,
+	digits: u32,
+	unit: &str,
+	algorithm: Algorithm,
+) -> String {
+	let width = digits as usize;
 	let tokens: Vec<String> = seeds
 		.iter()
-		.map(|s| format!("{:06}", generate_token(s, time, window)))
+		.map(|s| format!("{:0width$}", generate_token(s, time, window, digits, unit, algorithm), width = width))
 		.collect();
 	tokens.join("-")
 }
@@ -68,34 +169,37 @@ pub fn generate_combined_token(seeds: [&str; 6], time: u64, window: u64) -> Stri
 ///
 /// # Arguments
 ///
-/// * `seeds` - An array of six secret seeds used to generate the token.
+/// * `seeds` - An array of six shared secrets used to generate the token.
 /// * `time` - The current UNIX timestamp in seconds.
 /// * `token` - The combined token string to verify.
-/// * `window` - The time step window size in seconds.
+/// * `window` - The time step window size, expressed in `unit`s.
+/// * `digits` - The number of digits each token should have (typically 6, 7, or 8).
 /// * `allowed_windows` - The number of time windows (before and after) to allow for drift.
-/// * `unit` - The time unit (e.g., `"s"` for seconds; currently unused placeholder).
+/// * `unit` - The unit `window` is expressed in: `"s"`, `"m"`, or `"ms"`.
+/// * `algorithm` - The HMAC hash algorithm to derive each token with.
 ///
 /// # Returns
 ///
 /// `true` if the token is valid within the allowed window range, otherwise `false`.
 ///
-/// # Example
-///
-/// ```
-/// let seeds = ["a", "b", "c", "d", "e", "f"];
-/// let now = current_unix_time();
-/// let token = generate_combined_token(seeds, now, 30);
-/// assert!(verify_combined_token(seeds, now, &token, 30, 1, "s"));
-/// ```
+/// Drift is accumulated in milliseconds and only converted to whole seconds
+/// after multiplying by `step`, since `time` itself has one-second
+/// resolution. A sub-second `"ms"` window combined with `allowed_windows`
+/// small enough that `step * step_millis` stays under 1000 still can't move
+/// `time` by a fractional second, so those steps collapse onto the current
+/// one rather than being skipped outright.
+#[allow(clippy::too_many_arguments)]
 pub fn verify_combined_token(
-	seeds: [&str; 6],
+	seeds: [&Secret; 6],
 	time: u64,
 	token: &str,
 	window: u64,
+	digits: u32,
 	allowed_windows: u32,
 	unit: &str,
+	algorithm: Algorithm,
 ) -> bool {
-	let delta = if unit == "s" { 1 } else { 1 };
+	let step_millis = window * unit_millis(unit);
 	let steps = match allowed_windows {
 		0 | 1 => vec![0],
 		n => {
@@ -112,9 +216,9 @@ pub fn verify_combined_token(
 		let t = if step == 0 {
 			time
 		} else {
-			time.wrapping_add_signed(step * (window * delta) as i64)
+			time.wrapping_add_signed((step * step_millis as i64) / 1000)
 		};
-		let gen_token = generate_combined_token(seeds, t, window);
+		let gen_token = generate_combined_token(seeds, t, window, digits, unit, algorithm);
 		if gen_token == token {
 			return true;
 		}
@@ -124,6 +228,9 @@ pub fn verify_combined_token(
 
 /// Get the current UNIX timestamp in seconds.
 ///
+/// Requires the default `std` feature; under `no_std` the caller must supply
+/// `time` directly to every function above instead.
+///
 /// # Returns
 ///
 /// The number of seconds since the UNIX epoch (January 1, 1970).
@@ -131,12 +238,181 @@ pub fn verify_combined_token(
 /// # Example
 ///
 /// ```
+/// use totp::current_unix_time;
+///
 /// let now = current_unix_time();
 /// println!("Current timestamp: {}", now);
 /// ```
+#[cfg(feature = "std")]
 pub fn current_unix_time() -> u64 {
 	SystemTime::now()
 		.duration_since(UNIX_EPOCH)
 		.unwrap()
 		.as_secs()
 }
+
+/// Seconds remaining before the token for `time` rolls over to the next
+/// `window`.
+///
+/// `unit` (`"s"`, `"m"`, or `"ms"`) must match the one passed to
+/// `generate_token`/`generate_combined_token`, so the rollover this reports
+/// agrees with the token actually in effect.
+///
+/// # Example
+///
+/// ```
+/// use totp::ttl;
+///
+/// assert_eq!(ttl(1700000005, 30, "s"), 5);
+/// ```
+pub fn ttl(time: u64, window: u64, unit: &str) -> u64 {
+	let window_millis = window * unit_millis(unit);
+	let time_millis = time * 1000;
+	(window_millis - (time_millis % window_millis)) / 1000
+}
+
+/// The UNIX timestamp at which the token covering `time` rolls over to the
+/// next `window`.
+///
+/// `unit` (`"s"`, `"m"`, or `"ms"`) must match the one passed to
+/// `generate_token`/`generate_combined_token`, so the rollover this reports
+/// agrees with the token actually in effect.
+///
+/// # Example
+///
+/// ```
+/// use totp::next_step;
+///
+/// assert_eq!(next_step(1700000005, 30, "s"), 1700000010);
+/// ```
+pub fn next_step(time: u64, window: u64, unit: &str) -> u64 {
+	let window_millis = window * unit_millis(unit);
+	let time_millis = time * 1000;
+	((time_millis / window_millis + 1) * window_millis) / 1000
+}
+
+/// Generate a combined TOTP token for the current system time.
+///
+/// Equivalent to calling [`generate_combined_token`] with [`current_unix_time`].
+/// Requires the default `std` feature.
+#[cfg(feature = "std")]
+pub fn generate_combined_token_current(
+	seeds: [&Secret; 6],
+	window: u64,
+	digits: u32,
+	unit: &str,
+	algorithm: Algorithm,
+) -> String {
+	generate_combined_token(seeds, current_unix_time(), window, digits, unit, algorithm)
+}
+
+/// Verify a combined TOTP token against the current system time.
+///
+/// Equivalent to calling [`verify_combined_token`] with [`current_unix_time`].
+/// Requires the default `std` feature.
+#[cfg(feature = "std")]
+pub fn verify_combined_token_current(
+	seeds: [&Secret; 6],
+	token: &str,
+	window: u64,
+	digits: u32,
+	allowed_windows: u32,
+	unit: &str,
+	algorithm: Algorithm,
+) -> bool {
+	verify_combined_token(seeds, current_unix_time(), token, window, digits, allowed_windows, unit, algorithm)
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	// RFC 6238 Appendix B known-answer test vectors (8-digit tokens, 30s step).
+	const SEED_SHA1: &str = "12345678901234567890";
+	const SEED_SHA256: &str = "12345678901234567890123456789012";
+	const SEED_SHA512: &str = "1234567890123456789012345678901234567890123456789012345678901234";
+
+	#[test]
+	fn generate_token_matches_rfc6238_sha1_vectors() {
+		let seed = Secret::raw(SEED_SHA1);
+		let cases = [
+			(59, 94287082),
+			(1111111109, 7081804),
+			(1111111111, 14050471),
+			(1234567890, 89005924),
+			(2000000000, 69279037),
+			(20000000000, 65353130),
+		];
+		for (time, expected) in cases {
+			assert_eq!(generate_token(&seed, time, 30, 8, "s", Algorithm::Sha1), expected);
+		}
+	}
+
+	#[test]
+	fn generate_token_matches_rfc6238_sha256_vectors() {
+		let seed = Secret::raw(SEED_SHA256);
+		let cases = [
+			(59, 46119246),
+			(1111111109, 68084774),
+			(1111111111, 67062674),
+			(1234567890, 91819424),
+			(2000000000, 90698825),
+			(20000000000, 77737706),
+		];
+		for (time, expected) in cases {
+			assert_eq!(generate_token(&seed, time, 30, 8, "s", Algorithm::Sha256), expected);
+		}
+	}
+
+	#[test]
+	fn generate_token_matches_rfc6238_sha512_vectors() {
+		let seed = Secret::raw(SEED_SHA512);
+		let cases = [
+			(59, 90693936),
+			(1111111109, 25091201),
+			(1111111111, 99943326),
+			(1234567890, 93441116),
+			(2000000000, 38618901),
+			(20000000000, 47863826),
+		];
+		for (time, expected) in cases {
+			assert_eq!(generate_token(&seed, time, 30, 8, "s", Algorithm::Sha512), expected);
+		}
+	}
+
+	#[test]
+	fn ttl_reports_seconds_remaining_in_the_current_window() {
+		assert_eq!(ttl(1700000005, 30, "s"), 5);
+		assert_eq!(ttl(1700000010, 30, "s"), 30);
+	}
+
+	#[test]
+	fn next_step_reports_the_next_rollover_timestamp() {
+		assert_eq!(next_step(1700000005, 30, "s"), 1700000010);
+		assert_eq!(next_step(1700000010, 30, "s"), 1700000040);
+	}
+
+	#[test]
+	fn verify_combined_token_accepts_a_token_it_generated() {
+		let seeds = ["a", "b", "c", "d", "e", "f"].map(Secret::raw);
+		let seeds = [&seeds[0], &seeds[1], &seeds[2], &seeds[3], &seeds[4], &seeds[5]];
+		let token = generate_combined_token(seeds, 1700000000, 30, 6, "s", Algorithm::Sha1);
+		assert!(verify_combined_token(seeds, 1700000000, &token, 30, 6, 1, "s", Algorithm::Sha1));
+	}
+
+	#[test]
+	fn verify_combined_token_accepts_a_drifted_token_within_allowed_windows() {
+		let seeds = ["a", "b", "c", "d", "e", "f"].map(Secret::raw);
+		let seeds = [&seeds[0], &seeds[1], &seeds[2], &seeds[3], &seeds[4], &seeds[5]];
+		let token = generate_combined_token(seeds, 1700000000, 30, 6, "s", Algorithm::Sha1);
+		assert!(verify_combined_token(seeds, 1700000031, &token, 30, 6, 2, "s", Algorithm::Sha1));
+	}
+
+	#[test]
+	fn verify_combined_token_rejects_a_token_outside_allowed_windows() {
+		let seeds = ["a", "b", "c", "d", "e", "f"].map(Secret::raw);
+		let seeds = [&seeds[0], &seeds[1], &seeds[2], &seeds[3], &seeds[4], &seeds[5]];
+		let token = generate_combined_token(seeds, 1700000000, 30, 6, "s", Algorithm::Sha1);
+		assert!(!verify_combined_token(seeds, 1700000100, &token, 30, 6, 1, "s", Algorithm::Sha1));
+	}
+}